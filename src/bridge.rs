@@ -0,0 +1,154 @@
+use futures::FutureExt;
+use snafu::ResultExt;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self},
+};
+use tokio::task::JoinHandle;
+use zenoh::prelude::r#async::*;
+use zenoh::prelude::SplitBuffer;
+
+use super::error::*;
+use crate::node::Node;
+use crate::session::Session;
+use crate::subscriber::Abort;
+
+/// Generic bridge error
+pub struct BridgeError(pub Box<dyn Error + Send + Sync>);
+
+impl snafu::AsErrorSource for BridgeError {
+    fn as_error_source(&self) -> &(dyn Error + 'static) {
+        &*self.0
+    }
+}
+
+impl<E> From<E> for BridgeError
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn from(inner: E) -> Self {
+        Self(Box::new(inner))
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// Bridge join handle
+type BridgeTaskJoinHandle = JoinHandle<Result<(), BridgeError>>;
+
+/// Relays raw (undecoded) samples from a set of source key expressions on one node/session to
+/// destination key expressions on another. Each link puts every sample it receives on its
+/// destination key expression exactly as given: the sample's own key expression is not carried
+/// over or prefix-rewritten, so all samples matched by a wildcard source land on that one
+/// destination. Payloads are forwarded as opaque bytes, never decoded as prost, so a bridge can
+/// relay topics it does not itself know the message type of.
+pub struct Bridge {
+    src: Session,
+    dst: Session,
+    links: Vec<(String, String)>,
+    tasks: HashMap<(String, String), BridgeTaskJoinHandle>,
+}
+
+impl Bridge {
+    /// Creates a bridge forwarding samples from `src` to `dst`; call [`Bridge::add_link`] to
+    /// configure which key expressions are relayed, then [`Bridge::run`] to start forwarding
+    pub fn new(src: &Node, dst: &Node) -> Self {
+        Self {
+            src: src.session(),
+            dst: dst.session(),
+            links: Vec::new(),
+            tasks: HashMap::new(),
+        }
+    }
+
+    /// Adds a link forwarding every sample published on `src_keyexpr` to `dst_keyexpr`
+    pub fn add_link(&mut self, src_keyexpr: &str, dst_keyexpr: &str) {
+        self.links
+            .push((src_keyexpr.to_owned(), dst_keyexpr.to_owned()));
+    }
+
+    /// Declares a raw subscriber and starts forwarding for every configured link. Safe to call
+    /// repeatedly: a link that already has a live forwarding task is left running; a link whose
+    /// task has since died (e.g. a `put` to the destination failed) is reaped and respawned, so
+    /// calling `run()` again is how a dead link gets restarted.
+    pub async fn run(&mut self) -> Result<(), NodeError> {
+        for link in self.links.clone() {
+            if self.is_link_active(&link) {
+                continue;
+            }
+
+            let (src_keyexpr, dst_keyexpr) = link;
+            let handle = tokio::spawn(bridge_link_task(
+                self.src.clone(),
+                self.dst.clone(),
+                src_keyexpr.clone(),
+                dst_keyexpr.clone(),
+            ));
+
+            self.tasks.insert((src_keyexpr, dst_keyexpr), handle);
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `link` already has a forwarding task still running, reaping its entry
+    /// first if the task has already finished
+    fn is_link_active(&mut self, link: &(String, String)) -> bool {
+        if let Some(handle) = self.tasks.get_mut(link) {
+            if handle.now_or_never().is_none() {
+                return true;
+            }
+            self.tasks.remove(link);
+        }
+
+        false
+    }
+}
+
+impl Abort for Bridge {
+    /// Aborts all links forwarded by this bridge
+    fn abort(&self) {
+        for task in self.tasks.values() {
+            task.abort();
+        }
+    }
+}
+
+/// Forwards raw samples received on `src_keyexpr` to `dst_keyexpr`, without decoding them
+async fn bridge_link_task(
+    src: Session,
+    dst: Session,
+    src_keyexpr: String,
+    dst_keyexpr: String,
+) -> Result<(), BridgeError> {
+    let src_session = src.into_inner();
+    let dst_session = dst.into_inner();
+
+    let receiver = src_session
+        .declare_subscriber(src_keyexpr)
+        .res()
+        .await
+        .context(DeclareReceiverSnafu)?;
+
+    while let Ok(sample) = receiver.recv_async().await {
+        let payload = sample.payload.contiguous().into_owned();
+        dst_session
+            .put(dst_keyexpr.clone(), payload)
+            .res()
+            .await
+            .context(SendSnafu)?;
+    }
+
+    Ok(())
+}
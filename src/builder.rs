@@ -10,6 +10,9 @@ pub struct NodeBuilder {
 
     /// Optional path to zenoh json config
     config_path: Option<String>,
+
+    /// Optional node-wide topic prefix
+    topic_prefix: Option<String>,
 }
 
 impl NodeBuilder {
@@ -18,6 +21,7 @@ impl NodeBuilder {
         Self {
             network: None,
             config_path: None,
+            topic_prefix: None,
         }
     }
     /// Builds a zenoh node
@@ -33,7 +37,7 @@ impl NodeBuilder {
             let _ = config.scouting.multicast.set_address(Some(socket_addr));
         }
 
-        let node = Node::new(config).await?;
+        let node = Node::new(config, self.topic_prefix.clone()).await?;
         Ok(node)
     }
 
@@ -46,4 +50,10 @@ impl NodeBuilder {
     pub fn set_network(&mut self, nw: (String, u16)) {
         self.network = Some(nw);
     }
+
+    /// Setter for the node-wide topic prefix prepended (as `prefix/topic`) to every
+    /// publisher and subscriber key expression created by the resulting node
+    pub fn set_topic_prefix(&mut self, prefix: &str) {
+        self.topic_prefix = Some(prefix.to_string());
+    }
 }
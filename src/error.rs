@@ -59,4 +59,14 @@ pub enum NodeError {
     ScoutingConfigError {
         source: AddrParseError,
     },
+
+    /// Cannot create a queryable for the given key
+    DeclareQueryableError {
+        source: zenoh::Error,
+    },
+
+    /// Error querying the given topic
+    QueryError {
+        source: zenoh::Error,
+    },
 }
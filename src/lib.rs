@@ -0,0 +1,10 @@
+//! Typed, protobuf-based node wrapper around a zenoh session.
+
+pub mod bridge;
+pub mod builder;
+pub mod error;
+pub mod node;
+pub mod publisher;
+pub mod queryable;
+pub mod session;
+pub mod subscriber;
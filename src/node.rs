@@ -1,8 +1,12 @@
 use crate::error::NodeError;
-pub use crate::publisher::Publisher;
+pub use crate::publisher::{CongestionControl, Priority, Publisher, PublisherOptions};
+pub use crate::queryable::{Queryable, QueryableError, Respond};
 use crate::session::Session;
 pub use crate::subscriber::SubscriberImpl;
 pub use crate::subscriber::{Abort, Subscribe, Subscriber, SubscriberError};
+use futures::stream::Stream;
+use std::fmt::Debug;
+use std::sync::Arc;
 
 /// Node object wrapping a zenoh session
 /// # Examples
@@ -62,25 +66,70 @@ pub use crate::subscriber::{Abort, Subscribe, Subscriber, SubscriberError};
 pub struct Node {
     /// Zenoh session
     session: Session,
+
+    /// Prefix prepended (as `prefix/topic`) to every publisher and subscriber key expression
+    topic_prefix: Option<Arc<str>>,
 }
 
 impl Node {
-    /// Creates a zenoh node with a given config
-    pub async fn new(config: zenoh::config::Config) -> Result<Self, NodeError> {
+    /// Creates a zenoh node with a given config and optional node-wide topic prefix
+    pub async fn new(
+        config: zenoh::config::Config,
+        topic_prefix: Option<String>,
+    ) -> Result<Self, NodeError> {
         Ok(Self {
             session: Session::new(config).await?,
+            topic_prefix: topic_prefix.map(Arc::from),
         })
     }
 
-    /// Creates a new zenoh publisher publishing a prost message
+    /// Get the underlying zenoh session, for types (e.g. `Bridge`) that need to declare their
+    /// own subscribers or publishers outside of `Node`'s typed API
+    pub(crate) fn session(&self) -> Session {
+        self.session.clone()
+    }
+
+    /// Prepends the node's topic prefix, if any, to the given topic
+    fn full_topic(&self, topic: &str) -> String {
+        match &self.topic_prefix {
+            Some(prefix) => format!("{}/{}", prefix, topic),
+            None => topic.to_string(),
+        }
+    }
+
+    /// Creates a new zenoh publisher publishing a prost message with default QoS
     pub async fn new_publisher<T: prost::Message>(
         &self,
         topic: &str,
     ) -> Result<Publisher<T>, NodeError> {
-        let publisher = Publisher::new(topic, self.session.clone()).await?;
+        self.new_publisher_with_options(topic, PublisherOptions::default())
+            .await
+    }
+
+    /// Creates a new zenoh publisher publishing a prost message with the given QoS options
+    pub async fn new_publisher_with_options<T: prost::Message>(
+        &self,
+        topic: &str,
+        options: PublisherOptions,
+    ) -> Result<Publisher<T>, NodeError> {
+        let publisher =
+            Publisher::new(&self.full_topic(topic), self.session.clone(), options).await?;
         Ok(publisher)
     }
 
+    /// Subscribes to a prost message and returns a `Stream` of decoded messages, so callers can
+    /// apply `futures` combinators (`filter`, `map`, `take`, `timeout`, `zip`, ...) or `select!`
+    /// across several topics instead of implementing `Subscribe<T>`
+    pub async fn subscribe_stream<T>(
+        &self,
+        topic: &str,
+    ) -> Result<impl Stream<Item = Result<T, NodeError>>, NodeError>
+    where
+        T: prost::Message + Default + 'static,
+    {
+        crate::subscriber::subscribe_stream(self.session.clone(), self.full_topic(topic)).await
+    }
+
     /// Creates a new zenoh subscriber
     pub async fn new_subscriber<S>(&self, inner: S) -> Result<Subscriber<S>, NodeError> {
         let subscriber = Subscriber::new(self.session.clone(), inner);
@@ -93,7 +142,32 @@ impl Node {
         topic: &str,
         subscriber: &mut dyn SubscriberImpl<T>,
     ) -> Result<(), NodeError> {
-        let _ = subscriber.subscribe(topic).await?;
+        let _ = subscriber.subscribe(&self.full_topic(topic)).await?;
         Ok(())
     }
+
+    /// Declares a queryable on the given topic that answers every incoming query with `responder`
+    pub async fn new_queryable<Req, Resp, R>(
+        &self,
+        topic: &str,
+        responder: R,
+    ) -> Result<Queryable<Req, Resp>, NodeError>
+    where
+        Req: prost::Message + Default + Debug + 'static,
+        Resp: prost::Message + Default + Debug + 'static,
+        R: Respond<Req, Resp> + 'static,
+    {
+        let queryable =
+            Queryable::new(&self.full_topic(topic), self.session.clone(), responder).await?;
+        Ok(queryable)
+    }
+
+    /// Encodes `req`, queries every queryable declared on `topic`, and decodes each reply as `Resp`
+    pub async fn query<Req, Resp>(&self, topic: &str, req: Req) -> Result<Vec<Resp>, NodeError>
+    where
+        Req: prost::Message,
+        Resp: prost::Message + Default,
+    {
+        crate::queryable::query(self.session.clone(), &self.full_topic(topic), req).await
+    }
 }
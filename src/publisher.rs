@@ -1,10 +1,33 @@
 use snafu::ResultExt;
 use zenoh::prelude::r#async::AsyncResolve;
+pub use zenoh::publication::{CongestionControl, Priority};
 
 use super::error::*;
 use crate::session::Session;
 
-use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// Quality-of-service options used when a publisher is declared
+#[derive(Debug, Clone, Copy)]
+pub struct PublisherOptions {
+    /// Whether the publisher blocks or drops samples under network congestion
+    pub congestion_control: CongestionControl,
+    /// Scheduling priority given to samples published on this publisher
+    pub priority: Priority,
+}
+
+impl Default for PublisherOptions {
+    fn default() -> Self {
+        Self {
+            congestion_control: CongestionControl::Drop,
+            priority: Priority::Data,
+        }
+    }
+}
 
 /// Publisher that publishes protobuf message using a zenoh session
 #[derive(Debug, Clone)]
@@ -20,8 +43,12 @@ where
     T: prost::Message + Debug,
 {
     /// Creates new publisher that publishes on given topic using provided zenoh session
-    pub(super) async fn new(topic: &str, session: Session) -> Result<Publisher<T>, NodeError> {
-        let inner = PublisherInner::<T>::new(topic, session).await?;
+    pub(super) async fn new(
+        topic: &str,
+        session: Session,
+        options: PublisherOptions,
+    ) -> Result<Publisher<T>, NodeError> {
+        let inner = PublisherInner::<T>::new(topic, session, options).await?;
         let publisher = Self {
             inner: Arc::new(inner),
             _topic: Arc::from(topic),
@@ -39,27 +66,47 @@ where
 }
 
 /// Publisher helper
-#[derive(Debug, Clone)]
 struct PublisherInner<T>
 where
     T: prost::Message + Debug,
 {
-    session: Arc<zenoh::Session>,
+    publisher: zenoh::publication::Publisher<'static>,
     topic: Arc<str>,
     _phantom: PhantomData<T>,
 }
 
+impl<T> fmt::Debug for PublisherInner<T>
+where
+    T: prost::Message + Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PublisherInner")
+            .field("topic", &self.topic)
+            .finish()
+    }
+}
+
 impl<T> PublisherInner<T>
 where
     T: prost::Message + Debug,
 {
     /// Create new publisher helper
-    async fn new(topic: &str, session: Session) -> Result<PublisherInner<T>, NodeError> {
+    async fn new(
+        topic: &str,
+        session: Session,
+        options: PublisherOptions,
+    ) -> Result<PublisherInner<T>, NodeError> {
         let session = session.into_inner();
-        //let _key_expr = session.declare_keyexpr(topic).res().await.context(DeclarePublisherSnafu)?;
+        let publisher = session
+            .declare_publisher(topic.to_owned())
+            .congestion_control(options.congestion_control)
+            .priority(options.priority)
+            .res()
+            .await
+            .context(DeclarePublisherSnafu)?;
 
         Ok(Self {
-            session,
+            publisher,
             topic: Arc::from(topic),
             _phantom: PhantomData::default(),
         })
@@ -73,9 +120,8 @@ where
 
         match res {
             Ok(_) => {
-                let topic_str = self.topic.as_ref();
-                self.session
-                    .put(topic_str, buf)
+                self.publisher
+                    .put(buf)
                     .res()
                     .await
                     .context(DeclarePublisherSnafu)?;
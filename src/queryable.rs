@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use snafu::ResultExt;
+use std::{
+    error::Error,
+    fmt::{self, Debug},
+    marker::PhantomData,
+    sync::Arc,
+};
+use tokio::{sync::Mutex, task::JoinHandle};
+use zenoh::prelude::r#async::*;
+use zenoh::prelude::SplitBuffer;
+
+use super::error::*;
+use crate::session::Session;
+use crate::subscriber::Abort;
+
+/// Generic queryable error
+pub struct QueryableError(pub Box<dyn Error + Send + Sync>);
+
+impl snafu::AsErrorSource for QueryableError {
+    fn as_error_source(&self) -> &(dyn Error + 'static) {
+        &*self.0
+    }
+}
+
+impl<E> From<E> for QueryableError
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn from(inner: E) -> Self {
+        Self(Box::new(inner))
+    }
+}
+
+impl fmt::Display for QueryableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for QueryableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// Respond trait for answering a typed request with a typed response
+#[async_trait]
+pub trait Respond<Req, Resp>: Send
+where
+    Req: prost::Message,
+    Resp: prost::Message,
+{
+    async fn respond(&mut self, req: Req) -> Result<Resp, QueryableError>;
+}
+
+/// Queryable that answers prost `Req` queries with a prost `Resp` using a zenoh session
+pub struct Queryable<Req, Resp> {
+    handle: JoinHandle<Result<(), QueryableError>>,
+    _topic: Arc<str>,
+    _phantom: PhantomData<(Req, Resp)>,
+}
+
+impl<Req, Resp> Queryable<Req, Resp>
+where
+    Req: prost::Message + Default + Debug + 'static,
+    Resp: prost::Message + Default + Debug + 'static,
+{
+    /// Declares a queryable on the given topic that answers every incoming query with `responder`
+    pub(super) async fn new<R>(
+        topic: &str,
+        session: Session,
+        responder: R,
+    ) -> Result<Queryable<Req, Resp>, NodeError>
+    where
+        R: Respond<Req, Resp> + 'static,
+    {
+        let inner = Arc::new(Mutex::new(responder));
+        let handle = tokio::spawn(queryable_task(inner, session, topic.to_owned()));
+
+        Ok(Self {
+            handle,
+            _topic: Arc::from(topic),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<Req, Resp> Abort for Queryable<Req, Resp>
+where
+    Req: Send,
+    Resp: Send,
+{
+    /// Aborts the task answering queries for this queryable
+    fn abort(&self) {
+        self.handle.abort();
+    }
+}
+
+/// Encodes `req`, queries `topic` over the given session, and decodes every reply as `Resp`
+pub(crate) async fn query<Req, Resp>(
+    session: Session,
+    topic: &str,
+    req: Req,
+) -> Result<Vec<Resp>, NodeError>
+where
+    Req: prost::Message,
+    Resp: prost::Message + Default,
+{
+    let mut buf = Vec::new();
+    buf.reserve(req.encoded_len());
+    req.encode(&mut buf).map_err(|_| NodeError::EncodeError)?;
+
+    let session = session.into_inner();
+    let replies = session
+        .get(topic)
+        .with_value(buf)
+        .res()
+        .await
+        .context(QuerySnafu)?;
+
+    let mut responses = Vec::new();
+    while let Ok(reply) = replies.recv_async().await {
+        if let Ok(sample) = reply.sample {
+            let payload = sample.payload.contiguous();
+            let resp = Resp::decode(payload.as_ref())
+                .map_err(|error| Box::new(error) as Box<dyn Error + Send + Sync>)
+                .context(DeserializeSnafu {
+                    topic: topic.to_owned(),
+                })?;
+            responses.push(resp);
+        }
+    }
+
+    Ok(responses)
+}
+
+/// Queryable task to listen for and answer queries
+async fn queryable_task<Req, Resp, R>(
+    inner: Arc<Mutex<R>>,
+    session: Session,
+    topic: String,
+) -> Result<(), QueryableError>
+where
+    Req: prost::Message + Default + 'static,
+    Resp: prost::Message + 'static,
+    R: Respond<Req, Resp> + 'static,
+{
+    let session = session.into_inner();
+    let queryable = session
+        .declare_queryable(topic.clone())
+        .res()
+        .await
+        .context(DeclareQueryableSnafu)?;
+
+    while let Ok(query) = queryable.recv_async().await {
+        // A single malformed request or a handler `Err` must not take the whole queryable
+        // offline for every other caller, so every failure path below replies with an error
+        // and `continue`s rather than propagating out of the loop.
+        let req = match query.value() {
+            Some(value) => {
+                let payload = value.payload.contiguous();
+                match Req::decode(payload.as_ref()) {
+                    Ok(req) => req,
+                    Err(error) => {
+                        let _ = query.reply(Err(Value::from(error.to_string()))).res().await;
+                        continue;
+                    }
+                }
+            }
+            None => Req::default(),
+        };
+
+        let resp = match inner.lock().await.respond(req).await {
+            Ok(resp) => resp,
+            Err(error) => {
+                let _ = query.reply(Err(Value::from(error.to_string()))).res().await;
+                continue;
+            }
+        };
+
+        let mut buf = Vec::new();
+        buf.reserve(resp.encoded_len());
+        if resp.encode(&mut buf).is_err() {
+            let _ = query
+                .reply(Err(Value::from("failed to encode response")))
+                .res()
+                .await;
+            continue;
+        }
+
+        let sample = Sample::new(query.key_expr().clone(), buf);
+        let _ = query.reply(Ok(sample)).res().await;
+    }
+
+    Ok(())
+}
@@ -1,14 +1,24 @@
 use super::error::*;
 use async_trait::async_trait;
-use futures::{prelude::*, FutureExt};
+use futures::{
+    prelude::*,
+    stream::{self, Stream},
+    FutureExt,
+};
 use snafu::ResultExt;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     error::Error,
     fmt::{self},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    sync::{Mutex, Notify},
+    task::JoinHandle,
 };
-use tokio::{sync::Mutex, task::JoinHandle};
 use zenoh::prelude::r#async::*;
 use zenoh::prelude::SplitBuffer;
 
@@ -63,6 +73,7 @@ where
 struct SubscriberBase {
     session: Session,
     subscriptions: HashMap<String, SubscriberTaskJoinHandle>,
+    conflation_drops: HashMap<String, Arc<AtomicU64>>,
     name: String,
 }
 
@@ -91,19 +102,39 @@ impl SubscriberBase {
 /// Subscriber that wraps an object which implements subscribe traits for different messages
 pub struct Subscriber<S: ?Sized> {
     inner: Arc<Mutex<S>>,
+    /// Ring buffer capacity for topics subscribed in conflated mode; `None` means every topic
+    /// subscribed through this `Subscriber` awaits `on_data` inline, as usual
+    conflation_capacity: Option<usize>,
     base: SubscriberBase,
 }
 
 impl<S> Subscriber<S> {
     /// Creates new subscriber with given zenoh session and wrapper object
     pub fn new(session: Session, inner: S) -> Self {
+        Self::new_impl(session, inner, None)
+    }
+
+    /// Creates a new subscriber where every topic is subscribed in conflating (latest-value)
+    /// mode: the zenoh receive loop decodes messages into a bounded ring buffer of `capacity`
+    /// slots instead of awaiting `on_data` inline, overwriting the oldest slot once full, while a
+    /// separate loop feeds the buffer to `on_data` as fast as the callback allows. With
+    /// `capacity == 1` this yields pure "always deliver the latest, skip stale data" semantics.
+    /// A stalled `on_data` can therefore never apply backpressure to the network; use
+    /// [`Subscriber::dropped_count`] to observe how many messages conflation discarded.
+    pub fn new_conflated(session: Session, inner: S, capacity: usize) -> Self {
+        Self::new_impl(session, inner, Some(capacity.max(1)))
+    }
+
+    fn new_impl(session: Session, inner: S, conflation_capacity: Option<usize>) -> Self {
         let inner = Arc::new(Mutex::new(inner));
         let inner_addr = std::ptr::addr_of!(*inner) as usize;
         let name = format!("{}:{}", std::any::type_name::<S>(), inner_addr);
         Self {
             inner,
+            conflation_capacity,
             base: SubscriberBase {
                 subscriptions: HashMap::new(),
+                conflation_drops: HashMap::new(),
                 session,
                 name,
             },
@@ -115,6 +146,16 @@ impl<S> Subscriber<S> {
         self.inner.clone()
     }
 
+    /// Number of messages conflation has discarded on the given topic because `on_data` could
+    /// not keep up or because their payload could not be decoded, or `None` if the topic was
+    /// not subscribed in conflated mode
+    pub fn dropped_count(&self, topic: &str) -> Option<u64> {
+        self.base
+            .conflation_drops
+            .get(topic)
+            .map(|dropped| dropped.load(Ordering::Relaxed))
+    }
+
     /// Join all subscriptions for this subscriber
     pub async fn join(mut self) -> Result<S, Box<dyn Error + Send + Sync>> {
         if let Err(err) = future::try_join_all(self.base.subscriptions.drain().map(|(_, j)| {
@@ -176,11 +217,25 @@ where
         self.base.check_for_active_subscription(topic)?;
 
         let inner = self.inner.clone();
-        let handle = tokio::spawn(subscriber_task(
-            inner,
-            self.base.session.clone(),
-            topic.to_owned(),
-        ));
+        let handle = if let Some(capacity) = self.conflation_capacity {
+            let dropped = Arc::new(AtomicU64::new(0));
+            self.base
+                .conflation_drops
+                .insert(topic.to_owned(), dropped.clone());
+            let buffer = Arc::new(ConflatingBuffer::new(capacity, dropped));
+            tokio::spawn(conflated_subscriber_task(
+                inner,
+                self.base.session.clone(),
+                topic.to_owned(),
+                buffer,
+            ))
+        } else {
+            tokio::spawn(subscriber_task(
+                inner,
+                self.base.session.clone(),
+                topic.to_owned(),
+            ))
+        };
 
         self.base.subscriptions.insert(topic.to_owned(), handle);
 
@@ -188,6 +243,137 @@ where
     }
 }
 
+/// Bounded ring buffer used by a conflated subscription: the zenoh receive loop pushes decoded
+/// messages in and never waits on the consumer, so it can never block on a stalled `on_data`.
+/// When full, pushing a new message overwrites (and counts as dropped) the oldest buffered one.
+struct ConflatingBuffer<T> {
+    capacity: usize,
+    queue: Mutex<VecDeque<T>>,
+    dropped: Arc<AtomicU64>,
+    notify: Notify,
+}
+
+impl<T> ConflatingBuffer<T> {
+    fn new(capacity: usize, dropped: Arc<AtomicU64>) -> Self {
+        Self {
+            capacity,
+            queue: Mutex::new(VecDeque::with_capacity(capacity)),
+            dropped,
+            notify: Notify::new(),
+        }
+    }
+
+    /// Pushes a freshly decoded message, dropping the oldest buffered one if the ring is full
+    async fn push(&self, msg: T) {
+        let mut queue = self.queue.lock().await;
+        if queue.len() == self.capacity {
+            queue.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        queue.push_back(msg);
+        drop(queue);
+        self.notify.notify_one();
+    }
+
+    /// Waits for and pops the oldest still-buffered message
+    async fn pop(&self) -> T {
+        loop {
+            {
+                let mut queue = self.queue.lock().await;
+                if let Some(msg) = queue.pop_front() {
+                    return msg;
+                }
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
+/// Declares a zenoh subscriber and wraps it as a stream of decoded prost messages, so callers
+/// can apply `futures` combinators instead of implementing `Subscribe<T>`
+pub(crate) async fn subscribe_stream<T>(
+    session: Session,
+    topic: String,
+) -> Result<impl Stream<Item = Result<T, NodeError>>, NodeError>
+where
+    T: prost::Message + Default + 'static,
+{
+    let zsession = session.into_inner();
+    let receiver = zsession
+        .declare_subscriber(topic.clone())
+        .res()
+        .await
+        .context(DeclareReceiverSnafu)?;
+
+    Ok(stream::unfold(
+        (receiver, topic),
+        |(receiver, topic)| async move {
+            let sample = receiver.recv_async().await.ok()?;
+            let payload = sample.payload.contiguous();
+            let buf = payload.as_ref();
+            let item = T::decode(buf)
+                .map_err(|error| Box::new(error) as Box<dyn Error + Send + Sync>)
+                .context(DeserializeSnafu {
+                    topic: topic.clone(),
+                });
+
+            Some((item, (receiver, topic)))
+        },
+    ))
+}
+
+/// Subscriber task for conflated (latest-value) mode: a receive loop drains the zenoh channel
+/// into `buffer` as fast as samples arrive, and a dispatch loop concurrently pops from `buffer`
+/// and feeds `on_data`. Neither loop can block the other, so a stalled `on_data` never applies
+/// backpressure to the network.
+async fn conflated_subscriber_task<T>(
+    inner: Arc<Mutex<dyn Subscribe<T>>>,
+    session: Session,
+    topic: String,
+    buffer: Arc<ConflatingBuffer<T>>,
+) -> Result<(), SubscriberError>
+where
+    T: prost::Message + Default + 'static,
+{
+    let session = session.into_inner();
+    let receiver = session
+        .declare_subscriber(topic.clone())
+        .res()
+        .await
+        .context(DeclareReceiverSnafu)?;
+
+    let receive_loop = async {
+        while let Ok(sample) = receiver.recv_async().await {
+            let payload = sample.payload.contiguous();
+            let buf = payload.as_ref();
+            // A noisy/high-rate link is the exact use case conflation targets, so a single
+            // undecodable payload must not tear down the whole subscription: count it as a
+            // drop and keep draining the channel instead of returning `Err`.
+            match T::decode(buf) {
+                Ok(msg) => buffer.push(msg).await,
+                Err(_) => {
+                    buffer.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+        Ok(())
+    };
+
+    let dispatch_loop = async {
+        loop {
+            let msg = buffer.pop().await;
+            inner.lock().await.on_data(msg).await?;
+        }
+        #[allow(unreachable_code)]
+        Ok(())
+    };
+
+    tokio::select! {
+        res = receive_loop => res,
+        res = dispatch_loop => res,
+    }
+}
+
 /// Subscriber task to listen for messages
 async fn subscriber_task<T>(
     inner: Arc<Mutex<dyn Subscribe<T>>>,